@@ -5,20 +5,66 @@ use std::{
     str::FromStr,
 };
 
+use crate::codec::{decode_hex_block, decode_hex_header};
 use crate::error::Error;
 use crate::jsonrpc::minreq_http::Builder;
 use corepc_types::{
     bitcoin::{
-        block::Header, consensus::deserialize, hex::FromHex, Block, BlockHash, Transaction, Txid,
+        block::Header,
+        consensus::{deserialize, encode::serialize_hex},
+        hex::FromHex,
+        Amount, Block, BlockHash, OutPoint, ScriptBuf, Transaction, Txid, TxOut,
+    },
+    model::{
+        EstimateSmartFee, GetBlockCount, GetBlockFilter, GetBlockVerboseOne, GetRawMempool,
+        TestMempoolAccept,
     },
-    model::{GetBlockCount, GetBlockFilter, GetBlockVerboseOne, GetRawMempool},
 };
 use jsonrpc::{
     serde,
     serde_json::{self, json},
-    Transport,
+    Request, Transport,
 };
 
+/// Fee estimation mode passed to `estimatesmartfee`.
+#[derive(Clone, Copy, Debug, Default, Hash, Eq, PartialEq, serde::Serialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum EstimateMode {
+    /// Let bitcoind pick the default mode.
+    #[default]
+    Unset,
+    /// Prioritize not propagating a transaction that might not confirm within `conf_target`.
+    Conservative,
+    /// Prioritize a lower feerate, accepting a higher chance of missing `conf_target`.
+    Economical,
+}
+
+/// Anything that can be turned into the hex-encoded raw transaction bytes `sendrawtransaction`
+/// expects, so callers can pass either a parsed [`Transaction`] or an already-serialized hex
+/// string without the caller needing to serialize it themselves.
+pub trait RawTx {
+    /// Converts `self` into a hex-encoded raw transaction.
+    fn raw_hex(self) -> String;
+}
+
+impl RawTx for &Transaction {
+    fn raw_hex(self) -> String {
+        serialize_hex(self)
+    }
+}
+
+impl RawTx for &str {
+    fn raw_hex(self) -> String {
+        self.to_string()
+    }
+}
+
+impl RawTx for String {
+    fn raw_hex(self) -> String {
+        self
+    }
+}
+
 /// client authentication methods
 #[derive(Clone, Debug, Hash, Eq, PartialEq, Ord, PartialOrd)]
 pub enum Auth {
@@ -45,6 +91,64 @@ impl Auth {
     }
 }
 
+/// A [`Transport`] that re-reads a bitcoind cookie file before every request.
+///
+/// `Client::with_auth` used to read `Auth::CookieFile` exactly once, at construction time.
+/// That breaks long-lived clients: bitcoind rewrites `.cookie` with a fresh random password
+/// on every restart, so a client built before a restart starts failing every call with
+/// stale credentials. This transport instead rebuilds the underlying HTTP transport with
+/// the cookie file's current contents on each request, so a `Client` survives node restarts
+/// without needing to be rebuilt.
+#[derive(Debug)]
+struct CookieRefreshingTransport {
+    url: String,
+    timeout: std::time::Duration,
+    cookie_path: PathBuf,
+}
+
+impl CookieRefreshingTransport {
+    /// Reads the cookie file's current contents, trimmed of surrounding whitespace. Called
+    /// fresh on every request so a cookie rotated by a bitcoind restart is picked up
+    /// without rebuilding the client.
+    fn read_cookie(&self) -> std::result::Result<String, jsonrpc::Error> {
+        std::fs::read_to_string(&self.cookie_path)
+            .map(|s| s.trim().to_string())
+            .map_err(|e| jsonrpc::Error::Transport(Box::new(e)))
+    }
+
+    fn build_inner(&self) -> std::result::Result<impl Transport, jsonrpc::Error> {
+        let cookie = self.read_cookie()?;
+
+        let transport = Builder::new()
+            .url(&self.url)
+            .map_err(|e| {
+                jsonrpc::Error::Transport(format!("invalid URL: {e}").into())
+            })?
+            .timeout(self.timeout)
+            .cookie_auth(cookie)
+            .build();
+
+        Ok(transport)
+    }
+}
+
+impl Transport for CookieRefreshingTransport {
+    fn send_request(&self, req: Request) -> std::result::Result<jsonrpc::Response, jsonrpc::Error> {
+        self.build_inner()?.send_request(req)
+    }
+
+    fn send_batch(
+        &self,
+        reqs: &[Request],
+    ) -> std::result::Result<Vec<Option<jsonrpc::Response>>, jsonrpc::Error> {
+        self.build_inner()?.send_batch(reqs)
+    }
+
+    fn fmt_target(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.url)
+    }
+}
+
 // RPC Client.
 #[derive(Debug)]
 pub struct Client {
@@ -62,28 +166,38 @@ impl Client {
             return Err(Error::MissingAuthentication);
         }
 
-        let mut builder = Builder::new()
-            .url(url)
-            .map_err(|e| Error::InvalidResponse(format!("Invalid URL: {e}")))?
-            .timeout(std::time::Duration::from_secs(60));
+        let timeout = std::time::Duration::from_secs(60);
 
-        builder = match auth {
+        match auth {
             Auth::None => unreachable!(),
-            Auth::UserPass(user, pass) => builder.basic_auth(user, Some(pass)),
+            Auth::UserPass(user, pass) => {
+                let transport = Builder::new()
+                    .url(url)
+                    .map_err(|e| Error::InvalidResponse(format!("Invalid URL: {e}")))?
+                    .timeout(timeout)
+                    .basic_auth(user, Some(pass))
+                    .build();
+
+                Ok(Self {
+                    inner: jsonrpc::Client::with_transport(transport),
+                })
+            }
             Auth::CookieFile(path) => {
-                let cookie = std::fs::read_to_string(path)
-                    .map_err(|_| Error::InvalidCookieFile)?
-                    .trim()
-                    .to_string();
-                builder.cookie_auth(cookie)
+                // Fail fast if the path is obviously bad, even though the transport itself
+                // re-reads the file before every request.
+                std::fs::read_to_string(&path).map_err(|_| Error::InvalidCookieFile)?;
+
+                let transport = CookieRefreshingTransport {
+                    url: url.to_string(),
+                    timeout,
+                    cookie_path: path,
+                };
+
+                Ok(Self {
+                    inner: jsonrpc::Client::with_transport(transport),
+                })
             }
-        };
-
-        let transport = builder.build();
-
-        Ok(Self {
-            inner: jsonrpc::Client::with_transport(transport),
-        })
+        }
     }
 
     /// Creates a client to a bitcoind JSON-RPC server with transport.
@@ -107,6 +221,42 @@ impl Client {
 
         Ok(resp.result()?)
     }
+
+    /// Calls multiple RPC methods in a single batched HTTP round-trip.
+    ///
+    /// Each entry in `requests` is a `(method, args)` pair. The returned vector preserves
+    /// the input order and reports a per-item result, so one bad request doesn't fail the
+    /// whole batch.
+    pub fn call_batch<T>(
+        &self,
+        requests: &[(&str, &[serde_json::Value])],
+    ) -> Result<Vec<Result<T, Error>>, Error>
+    where
+        T: for<'de> serde::Deserialize<'de>,
+    {
+        let raw_params: Vec<Box<serde_json::value::RawValue>> = requests
+            .iter()
+            .map(|(_, args)| serde_json::value::to_raw_value(args))
+            .collect::<std::result::Result<_, _>>()?;
+
+        let reqs: Vec<Request> = requests
+            .iter()
+            .zip(&raw_params)
+            .map(|((method, _), raw)| self.inner.build_request(method, Some(raw)))
+            .collect();
+
+        let responses = self.inner.send_batch(&reqs)?;
+
+        Ok(responses
+            .into_iter()
+            .map(|resp| match resp {
+                Some(resp) => resp.result::<T>().map_err(Error::from),
+                None => Err(Error::InvalidResponse(
+                    "batch response missing an entry for this request".into(),
+                )),
+            })
+            .collect())
+    }
 }
 
 // `bitcoind` RPC methods
@@ -115,12 +265,46 @@ impl Client {
     pub fn get_block(&self, block_hash: &BlockHash) -> Result<Block, Error> {
         let hex_string: String = self.call("getblock", &[json!(block_hash), json!(0)])?;
 
-        let bytes: Vec<u8> = Vec::<u8>::from_hex(&hex_string).map_err(Error::HexToBytes)?;
+        decode_hex_block(&hex_string)
+    }
 
-        let block: Block = deserialize(&bytes)
-            .map_err(|e| Error::InvalidResponse(format!("failed to deserialize block: {e}")))?;
+    /// Fetches multiple full blocks in a single batched request.
+    pub fn get_blocks(&self, block_hashes: &[BlockHash]) -> Result<Vec<Result<Block, Error>>, Error> {
+        let args: Vec<[serde_json::Value; 2]> = block_hashes
+            .iter()
+            .map(|hash| [json!(hash), json!(0)])
+            .collect();
+        let requests: Vec<(&str, &[serde_json::Value])> =
+            args.iter().map(|a| ("getblock", a.as_slice())).collect();
+
+        let hex_results: Vec<Result<String, Error>> = self.call_batch(&requests)?;
+
+        Ok(hex_results
+            .into_iter()
+            .map(|hex_result| decode_hex_block(&hex_result?))
+            .collect())
+    }
 
-        Ok(block)
+    /// Fetches multiple block headers in a single batched request.
+    pub fn get_block_headers(
+        &self,
+        block_hashes: &[BlockHash],
+    ) -> Result<Vec<Result<Header, Error>>, Error> {
+        let args: Vec<[serde_json::Value; 2]> = block_hashes
+            .iter()
+            .map(|hash| [json!(hash), json!(false)])
+            .collect();
+        let requests: Vec<(&str, &[serde_json::Value])> = args
+            .iter()
+            .map(|a| ("getblockheader", a.as_slice()))
+            .collect();
+
+        let hex_results: Vec<Result<String, Error>> = self.call_batch(&requests)?;
+
+        Ok(hex_results
+            .into_iter()
+            .map(|hex_result| decode_hex_header(&hex_result?))
+            .collect())
     }
 
     /// Get block verboseone
@@ -172,13 +356,7 @@ impl Client {
     pub fn get_block_header(&self, block_hash: &BlockHash) -> Result<Header, Error> {
         let hex_string: String = self.call("getblockheader", &[json!(block_hash), json!(false)])?;
 
-        let bytes = Vec::<u8>::from_hex(&hex_string).map_err(Error::HexToBytes)?;
-
-        let header = deserialize(&bytes).map_err(|e| {
-            Error::InvalidResponse(format!("failed to deserialize block header: {e}"))
-        })?;
-
-        Ok(header)
+        decode_hex_header(&hex_string)
     }
 
     /// Get raw mempool
@@ -199,6 +377,111 @@ impl Client {
 
         Ok(transaction)
     }
+
+    /// Estimate smart fee
+    pub fn estimate_smart_fee(
+        &self,
+        conf_target: u16,
+        mode: EstimateMode,
+    ) -> Result<EstimateSmartFee, Error> {
+        let res: EstimateSmartFee =
+            self.call("estimatesmartfee", &[json!(conf_target), json!(mode)])?;
+        Ok(res)
+    }
+
+    /// Get txout proof
+    ///
+    /// Returns a serialized merkle proof that `txids` are included in a block. If
+    /// `block_hash` isn't given, the node looks the transactions up in its txindex (or
+    /// mempool) to find which block to prove against.
+    pub fn get_txout_proof(
+        &self,
+        txids: &[Txid],
+        block_hash: Option<BlockHash>,
+    ) -> Result<Vec<u8>, Error> {
+        let mut args = vec![json!(txids)];
+        if let Some(hash) = block_hash {
+            args.push(json!(hash));
+        }
+
+        let hex_string: String = self.call("gettxoutproof", &args)?;
+
+        Vec::<u8>::from_hex(&hex_string).map_err(Error::HexToBytes)
+    }
+
+    /// Verify txout proof
+    ///
+    /// Returns the `Txid`s that `proof` proves are included in a block, or an empty vector
+    /// if the proof is invalid.
+    pub fn verify_txout_proof(&self, proof: &str) -> Result<Vec<Txid>, Error> {
+        let txids: Vec<Txid> = self.call("verifytxoutproof", &[json!(proof)])?;
+        Ok(txids)
+    }
+
+    /// Get tx out
+    ///
+    /// Looks `outpoint` up in the UTXO set, optionally including the mempool. Returns
+    /// `None` when the output doesn't exist there - either it was never created, or it has
+    /// already been spent.
+    pub fn get_tx_out(
+        &self,
+        outpoint: &OutPoint,
+        include_mempool: bool,
+    ) -> Result<Option<TxOut>, Error> {
+        let raw: Option<serde_json::Value> = self.call(
+            "gettxout",
+            &[
+                json!(outpoint.txid),
+                json!(outpoint.vout),
+                json!(include_mempool),
+            ],
+        )?;
+
+        let Some(raw) = raw else {
+            return Ok(None);
+        };
+
+        let value = raw
+            .get("value")
+            .and_then(|v| v.as_f64())
+            .ok_or_else(|| Error::InvalidResponse("gettxout: missing 'value' field".into()))?;
+
+        let script_hex = raw
+            .get("scriptPubKey")
+            .and_then(|v| v.get("hex"))
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| {
+                Error::InvalidResponse("gettxout: missing 'scriptPubKey.hex' field".into())
+            })?;
+
+        let script_bytes = Vec::<u8>::from_hex(script_hex).map_err(Error::HexToBytes)?;
+
+        Ok(Some(TxOut {
+            value: Amount::from_btc(value)
+                .map_err(|e| Error::InvalidResponse(format!("gettxout: invalid value: {e}")))?,
+            script_pubkey: ScriptBuf::from_bytes(script_bytes),
+        }))
+    }
+
+    /// Send raw transaction
+    ///
+    /// Accepts either a [`Transaction`] or a pre-serialized hex string via [`RawTx`] and
+    /// broadcasts it to the network, returning its `Txid`.
+    pub fn send_raw_transaction<R: RawTx>(&self, tx: R) -> Result<Txid, Error> {
+        let hex = tx.raw_hex();
+        let res: String = self.call("sendrawtransaction", &[json!(hex)])?;
+        Ok(res.parse()?)
+    }
+
+    /// Test mempool accept
+    ///
+    /// Dry-runs whether `txs` would be accepted into the mempool as a package, without
+    /// actually broadcasting them.
+    pub fn test_mempool_accept(&self, txs: &[&Transaction]) -> Result<TestMempoolAccept, Error> {
+        let hexes: Vec<String> = txs.iter().map(|tx| serialize_hex(*tx)).collect();
+        let res: TestMempoolAccept = self.call("testmempoolaccept", &[json!(hexes)])?;
+        Ok(res)
+    }
 }
 
 #[cfg(test)]
@@ -238,3 +521,34 @@ mod test_auth {
         std::fs::remove_file(cookie_path).ok();
     }
 }
+
+#[cfg(test)]
+mod test_cookie_refreshing_transport {
+    use super::*;
+
+    #[test]
+    fn test_build_inner_rereads_cookie_file() {
+        let temp_dir = std::env::temp_dir();
+        let cookie_path = temp_dir.join("test_cookie_refreshing_transport");
+        std::fs::write(&cookie_path, "user:pass1").expect("failed to write cookie");
+
+        let transport = CookieRefreshingTransport {
+            url: "http://127.0.0.1:18443".to_string(),
+            timeout: std::time::Duration::from_secs(1),
+            cookie_path: cookie_path.clone(),
+        };
+
+        assert_eq!(transport.read_cookie().unwrap(), "user:pass1");
+        assert!(transport.build_inner().is_ok());
+
+        // Simulate bitcoind rotating the cookie on restart: the transport must pick up the
+        // new value on the next request instead of keeping the one read at construction.
+        std::fs::write(&cookie_path, "user:pass2").expect("failed to overwrite cookie");
+
+        assert_eq!(transport.read_cookie().unwrap(), "user:pass2");
+        assert!(transport.build_inner().is_ok());
+
+        std::fs::remove_file(&cookie_path).ok();
+        assert!(transport.build_inner().is_err());
+    }
+}
@@ -0,0 +1,25 @@
+//! Shared hex-decode + consensus-deserialize helpers for block/header RPC responses.
+//!
+//! `Client` and `AsyncClient` both call `getblock`/`getblockheader` and get back the same
+//! hex-encoded consensus-serialized bytes back from bitcoind; this is the one place that
+//! decodes them, so the error wording and behavior can't drift between the two clients.
+
+use corepc_types::bitcoin::{block::Header, consensus::deserialize, hex::FromHex, Block};
+
+use crate::error::Error;
+
+/// Decodes a hex-encoded `getblock` response into a [`Block`].
+pub(crate) fn decode_hex_block(hex_string: &str) -> Result<Block, Error> {
+    let bytes = Vec::<u8>::from_hex(hex_string).map_err(Error::HexToBytes)?;
+
+    deserialize(&bytes)
+        .map_err(|e| Error::InvalidResponse(format!("failed to deserialize block: {e}")))
+}
+
+/// Decodes a hex-encoded `getblockheader` response into a [`Header`].
+pub(crate) fn decode_hex_header(hex_string: &str) -> Result<Header, Error> {
+    let bytes = Vec::<u8>::from_hex(hex_string).map_err(Error::HexToBytes)?;
+
+    deserialize(&bytes)
+        .map_err(|e| Error::InvalidResponse(format!("failed to deserialize block header: {e}")))
+}
@@ -0,0 +1,134 @@
+//! Reorg-aware block emitter for incremental wallet sync.
+
+use corepc_types::bitcoin::{Block, BlockHash};
+
+use crate::client::Client;
+use crate::error::Error;
+
+/// A `(height, hash)` pair identifying a block the emitter has already emitted.
+pub type CheckPoint = (u32, BlockHash);
+
+/// An event produced by [`Emitter::next`].
+#[derive(Debug, Clone)]
+pub enum Emission {
+    /// A new block connected onto the current tip.
+    Block { height: u32, block: Block },
+    /// The chain reorged; every checkpoint at or above `from_height` should be undone.
+    Reorg { from_height: u32 },
+}
+
+/// Walks a connected bitcoind node forward from a starting checkpoint, yielding newly
+/// connected blocks and reorg notifications one [`Emission`] at a time.
+///
+/// The emitter keeps a stack of previously emitted `(height, hash)` checkpoints, seeded
+/// with the starting checkpoint passed to [`Emitter::new`]. On every call to
+/// [`next`](Emitter::next) - including the very first, and even once every pushed
+/// checkpoint has been popped back down to that seed - it checks whether the node still
+/// agrees with the stored tip. If the tip's hash has changed out from under it, it pops
+/// that checkpoint and reports a [`Emission::Reorg`] so the caller can undo it. Should the
+/// reorg run deeper than anything the emitter has recorded (including the original seed),
+/// it keeps walking backward one height at a time - using the node's own
+/// `get_block_hash` as the only available source of truth once its own
+/// checkpoints are exhausted - until it reaches a height whose hash the node still agrees
+/// with on a later call, bottoming out at height 0 whose hash can never change. Once the
+/// stored tip matches the node, it fetches the next height forward and only emits it as
+/// connected if its `prev_blockhash` chains onto the current tip; if the node reorgs again
+/// in the narrow window between those two fetches, `next` retries internally against the
+/// node's latest state rather than returning `None`, since that sentinel is reserved for
+/// genuinely having caught up with the node's tip.
+#[derive(Debug)]
+pub struct Emitter<'c> {
+    client: &'c Client,
+    checkpoints: Vec<CheckPoint>,
+}
+
+/// Bounds the number of times [`Emitter::next`] will retry a block fetch that raced with a
+/// concurrent reorg, so a node that is pathologically reorging on every poll can't hang a
+/// caller forever inside a single call.
+const MAX_RACE_RETRIES: u32 = 10;
+
+impl<'c> Emitter<'c> {
+    /// Creates an emitter that starts walking forward from `start_height`/`start_hash`.
+    pub fn new(client: &'c Client, start_height: u32, start_hash: BlockHash) -> Self {
+        Self {
+            client,
+            checkpoints: vec![(start_height, start_hash)],
+        }
+    }
+
+    /// Returns the last checkpoint the emitter has confirmed as connected.
+    pub fn tip(&self) -> CheckPoint {
+        *self.checkpoints.last().expect("always has a checkpoint")
+    }
+
+    /// Produces the next [`Emission`], or `None` if the emitter has caught up with the
+    /// node's current tip.
+    pub fn next(&mut self) -> Result<Option<Emission>, Error> {
+        for _ in 0..MAX_RACE_RETRIES {
+            let (tip_height, tip_hash) = self.tip();
+            let node_height = self.client.get_block_count()?;
+
+            // The stored tip can only be checked against the node if the node has
+            // actually reached that height; if it hasn't (e.g. it's itself mid-reorg and
+            // temporarily shorter), leave the checkpoint alone and wait for the node to
+            // catch up. This check is unconditional on the size of `checkpoints` - even
+            // the original seed checkpoint from `Emitter::new` must be re-validated,
+            // since it may already be stale by the time the emitter starts walking.
+            if tip_height as u64 <= node_height {
+                let node_hash_at_tip = self.client.get_block_hash(tip_height)?;
+                if node_hash_at_tip != tip_hash {
+                    self.checkpoints.pop();
+
+                    if self.checkpoints.is_empty() {
+                        // We've unwound past every checkpoint we've ever recorded,
+                        // including the seed. There's no stored hash left to compare
+                        // against below this point, so fall back to whatever the node
+                        // currently reports one height down and keep walking backward
+                        // from there; a later call will re-validate it in turn. Height 0
+                        // (genesis) is the floor: its hash is fixed by the network and
+                        // can never actually mismatch.
+                        if tip_height == 0 {
+                            return Err(Error::InvalidResponse(
+                                "reorg walked back past the genesis block".into(),
+                            ));
+                        }
+
+                        let prev_height = tip_height - 1;
+                        let prev_hash = self.client.get_block_hash(prev_height)?;
+                        self.checkpoints.push((prev_height, prev_hash));
+                    }
+
+                    return Ok(Some(Emission::Reorg {
+                        from_height: tip_height,
+                    }));
+                }
+            }
+
+            let next_height = tip_height + 1;
+            if next_height as u64 > node_height {
+                return Ok(None);
+            }
+
+            let next_hash = self.client.get_block_hash(next_height)?;
+            let block = self.client.get_block(&next_hash)?;
+
+            if block.header.prev_blockhash != tip_hash {
+                // The node's chain moved under us between the checks above and this
+                // fetch. This is a transient race, not having caught up with the node,
+                // so retry from the node's latest state rather than returning the same
+                // `None` a caller would otherwise read as "sync finished".
+                continue;
+            }
+
+            self.checkpoints.push((next_height, next_hash));
+            return Ok(Some(Emission::Block {
+                height: next_height,
+                block,
+            }));
+        }
+
+        Err(Error::InvalidResponse(
+            "gave up retrying after the node kept reorging mid-fetch".into(),
+        ))
+    }
+}
@@ -4,10 +4,15 @@
 //! It supports multiple authentication methods and provides a type-safe interface for
 //! making RPC calls to a Bitcoin Core daemon.
 
+mod async_client;
 mod client;
+mod codec;
+mod emitter;
 mod error;
 
-pub use client::{Auth, Client};
+pub use async_client::{AsyncClient, AsyncResult, AsyncTransport, BlockingAsyncTransport};
+pub use client::{Auth, Client, EstimateMode, RawTx};
+pub use emitter::{CheckPoint, Emission, Emitter};
 pub use error::{Error, Result};
 
 pub use jsonrpc;
@@ -7,8 +7,10 @@
 //! bitcoind -regtest -rpcuser=bitcoin -rpcpassword=bitcoin -rpcport=18443
 //! ```
 
-use bdk_bitcoind_client::{Auth, Client, Error};
-use corepc_types::bitcoin::{BlockHash, Txid};
+use bdk_bitcoind_client::{Auth, Client, Emission, Emitter, EstimateMode, Error};
+use corepc_types::bitcoin::{
+    consensus::deserialize, hex::FromHex, BlockHash, OutPoint, Transaction, Txid,
+};
 use jsonrpc::serde_json::json;
 use std::{path::PathBuf, str::FromStr};
 
@@ -343,6 +345,323 @@ fn test_get_raw_transaction_invalid_txid() {
     assert!(result.is_err());
 }
 
+#[test]
+#[ignore]
+fn test_get_blocks_batch() {
+    let client = test_client();
+
+    let genesis_hash = client
+        .get_block_hash(0)
+        .expect("failed to get genesis hash");
+    let hashes = mine_blocks(&client, 2).expect("failed to mine blocks");
+    let mined_hash = BlockHash::from_str(&hashes[0]).expect("invalid hash");
+
+    let results = client.get_blocks(&[genesis_hash, mined_hash]);
+    let results = results.expect("batch call should succeed");
+
+    assert_eq!(results.len(), 2);
+    assert_eq!(
+        results[0].as_ref().expect("genesis block").block_hash(),
+        genesis_hash
+    );
+    assert_eq!(
+        results[1].as_ref().expect("mined block").block_hash(),
+        mined_hash
+    );
+}
+
+#[test]
+#[ignore]
+fn test_get_blocks_batch_reports_per_item_errors() {
+    let client = test_client();
+
+    let genesis_hash = client
+        .get_block_hash(0)
+        .expect("failed to get genesis hash");
+    let unknown_hash =
+        BlockHash::from_str("0000000000000000000000000000000000000000000000000000000000000000")
+            .unwrap();
+
+    let results = client
+        .get_blocks(&[genesis_hash, unknown_hash])
+        .expect("the batch call itself should succeed");
+
+    assert_eq!(results.len(), 2);
+    assert_eq!(
+        results[0].as_ref().expect("genesis block").block_hash(),
+        genesis_hash
+    );
+    assert!(
+        results[1].is_err(),
+        "an unknown hash should fail only its own entry, not the whole batch"
+    );
+}
+
+#[test]
+#[ignore]
+fn test_get_block_headers_batch() {
+    let client = test_client();
+
+    let genesis_hash = client
+        .get_block_hash(0)
+        .expect("failed to get genesis hash");
+    let hashes = mine_blocks(&client, 2).expect("failed to mine blocks");
+    let mined_hash = BlockHash::from_str(&hashes[0]).expect("invalid hash");
+
+    let results = client
+        .get_block_headers(&[genesis_hash, mined_hash])
+        .expect("batch call should succeed");
+
+    assert_eq!(results.len(), 2);
+    assert_eq!(
+        results[0].as_ref().expect("genesis header").block_hash(),
+        genesis_hash
+    );
+    assert_eq!(
+        results[1].as_ref().expect("mined header").block_hash(),
+        mined_hash
+    );
+}
+
+#[test]
+#[ignore]
+fn test_get_block_headers_batch_reports_per_item_errors() {
+    let client = test_client();
+
+    let genesis_hash = client
+        .get_block_hash(0)
+        .expect("failed to get genesis hash");
+    let unknown_hash =
+        BlockHash::from_str("0000000000000000000000000000000000000000000000000000000000000000")
+            .unwrap();
+
+    let results = client
+        .get_block_headers(&[genesis_hash, unknown_hash])
+        .expect("the batch call itself should succeed");
+
+    assert_eq!(results.len(), 2);
+    assert_eq!(
+        results[0].as_ref().expect("genesis header").block_hash(),
+        genesis_hash
+    );
+    assert!(
+        results[1].is_err(),
+        "an unknown hash should fail only its own entry, not the whole batch"
+    );
+}
+
+#[test]
+#[ignore]
+fn test_emitter_emits_connected_blocks() {
+    let client = test_client();
+
+    let start_height: u32 = client
+        .get_block_count()
+        .expect("failed to get block count")
+        .try_into()
+        .unwrap();
+    let start_hash = client
+        .get_block_hash(start_height)
+        .expect("failed to get block hash");
+
+    mine_blocks(&client, 3).expect("failed to mine blocks");
+
+    let mut emitter = Emitter::new(&client, start_height, start_hash);
+    let mut seen = 0;
+    while let Some(emission) = emitter.next().expect("emitter should not error") {
+        match emission {
+            Emission::Block { height, block } => {
+                assert_eq!(height, start_height + seen + 1);
+                assert!(!block.txdata.is_empty());
+                seen += 1;
+            }
+            Emission::Reorg { .. } => panic!("unexpected reorg on a linear chain"),
+        }
+    }
+
+    assert_eq!(seen, 3);
+    assert_eq!(emitter.tip().0, start_height + 3);
+}
+
+#[test]
+#[ignore]
+fn test_emitter_detects_reorg_past_seed_checkpoint() {
+    let client = test_client();
+
+    mine_blocks(&client, 5).expect("failed to mine blocks");
+    let seed_height: u32 = client
+        .get_block_count()
+        .expect("failed to get block count")
+        .try_into()
+        .unwrap();
+    let seed_hash = client
+        .get_block_hash(seed_height)
+        .expect("failed to get block hash");
+
+    // Reorg the seed height itself out from under the emitter - not just a checkpoint it
+    // pushed after construction - by invalidating it and mining a longer replacement chain.
+    let _: serde_json::Value = client
+        .call("invalidateblock", &[json!(seed_hash)])
+        .expect("failed to invalidate block");
+    mine_blocks(&client, 3).expect("failed to mine replacement chain");
+
+    let replacement_hash = client
+        .get_block_hash(seed_height)
+        .expect("failed to get replacement block hash");
+    assert_ne!(
+        replacement_hash, seed_hash,
+        "test setup should have reorged the seed height"
+    );
+
+    let mut emitter = Emitter::new(&client, seed_height, seed_hash);
+
+    let first = emitter
+        .next()
+        .expect("emitter should not error")
+        .expect("emitter should report something");
+    match first {
+        Emission::Reorg { from_height } => assert_eq!(from_height, seed_height),
+        Emission::Block { .. } => panic!("expected a reorg before any new blocks"),
+    }
+
+    let mut reconnected = 0;
+    while let Some(emission) = emitter.next().expect("emitter should not error") {
+        if let Emission::Block { height, .. } = emission {
+            assert!(height >= seed_height);
+            reconnected += 1;
+        }
+    }
+
+    assert!(reconnected > 0, "emitter should re-sync past the reorg");
+}
+
+#[test]
+#[ignore]
+fn test_estimate_smart_fee() {
+    let client = test_client();
+
+    mine_blocks(&client, 110).expect("failed to mine blocks");
+
+    let result = client
+        .estimate_smart_fee(6, EstimateMode::Conservative)
+        .expect("failed to estimate smart fee");
+
+    // Regtest has no real fee market, so the node may be unable to produce a feerate;
+    // either way it should report back the target it tried to estimate for.
+    assert!(result.blocks >= 1);
+}
+
+#[test]
+#[ignore]
+fn test_txout_proof_roundtrip() {
+    let client = test_client();
+
+    mine_blocks(&client, 1).expect("failed to mine block");
+
+    let best_hash = client
+        .get_best_block_hash()
+        .expect("failed to get best block hash");
+    let block = client.get_block(&best_hash).expect("failed to get block");
+    let txid = block.txdata[0].compute_txid();
+
+    let proof = client
+        .get_txout_proof(&[txid], Some(best_hash))
+        .expect("failed to get txout proof");
+    assert!(!proof.is_empty());
+
+    let proof_hex = proof.iter().map(|b| format!("{b:02x}")).collect::<String>();
+    let proven_txids = client
+        .verify_txout_proof(&proof_hex)
+        .expect("failed to verify txout proof");
+
+    assert_eq!(proven_txids, vec![txid]);
+}
+
+#[test]
+#[ignore]
+fn test_get_tx_out_unspent() {
+    let client = test_client();
+
+    mine_blocks(&client, 101).expect("failed to mine blocks");
+
+    let best_hash = client
+        .get_best_block_hash()
+        .expect("failed to get best block hash");
+    let block = client.get_block(&best_hash).expect("failed to get block");
+    let coinbase = &block.txdata[0];
+    let outpoint = OutPoint {
+        txid: coinbase.compute_txid(),
+        vout: 0,
+    };
+
+    let txout = client
+        .get_tx_out(&outpoint, false)
+        .expect("failed to get tx out")
+        .expect("coinbase output should be unspent");
+
+    assert_eq!(txout.value, coinbase.output[0].value);
+}
+
+#[test]
+#[ignore]
+fn test_get_tx_out_missing() {
+    let client = test_client();
+
+    let fake_txid =
+        Txid::from_str("0000000000000000000000000000000000000000000000000000000000000000")
+            .unwrap();
+    let outpoint = OutPoint {
+        txid: fake_txid,
+        vout: 0,
+    };
+
+    let result = client
+        .get_tx_out(&outpoint, true)
+        .expect("rpc call should succeed");
+
+    assert!(result.is_none());
+}
+
+#[test]
+#[ignore]
+fn test_send_raw_transaction_and_test_mempool_accept() {
+    let client = test_client();
+
+    mine_blocks(&client, 101).expect("failed to mine blocks");
+
+    let address: String = client
+        .call("getnewaddress", &[])
+        .expect("failed to get address");
+    let raw_hex: String = client
+        .call(
+            "createrawtransaction",
+            &[json!([]), json!({ &address: 0.001 })],
+        )
+        .expect("failed to create raw transaction");
+    let funded: serde_json::Value = client
+        .call("fundrawtransaction", &[json!(raw_hex)])
+        .expect("failed to fund raw transaction");
+    let signed: serde_json::Value = client
+        .call("signrawtransactionwithwallet", &[funded["hex"].clone()])
+        .expect("failed to sign raw transaction");
+    let signed_hex = signed["hex"].as_str().expect("missing signed hex");
+
+    let signed_bytes = Vec::<u8>::from_hex(signed_hex).expect("invalid signed hex");
+    let signed_tx: Transaction =
+        deserialize(&signed_bytes).expect("failed to deserialize signed transaction");
+
+    client
+        .test_mempool_accept(&[&signed_tx])
+        .expect("failed to test mempool accept");
+
+    let txid = client
+        .send_raw_transaction(signed_hex)
+        .expect("failed to send raw transaction");
+
+    let mempool = client.get_raw_mempool().expect("failed to get mempool");
+    assert!(mempool.contains(&txid));
+}
+
 #[test]
 #[ignore]
 fn test_get_block_filter() {